@@ -1,7 +1,27 @@
-use crate::{handle, RAIN_DENSITY};
+use crate::{handle, handle_grid, RAIN_DENSITY};
 use anyhow::Result;
 use std::io::{stdout, Write};
 
+// Classifies a point after stabilization: whether it's carrying no water,
+// sitting on a slope (a downhill neighbor by ground height), or at a local
+// low point of the ground with no lower neighbor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaterState {
+    Dry,
+    Flowing,
+    Pooled,
+}
+
+impl WaterState {
+    fn as_char(self) -> char {
+        match self {
+            WaterState::Dry => 'D',
+            WaterState::Flowing => 'F',
+            WaterState::Pooled => 'P',
+        }
+    }
+}
+
 // Functions required to solve problem.
 pub trait Landscape {
     type PointHeight: std::fmt::Debug + From<f64> + Clone;
@@ -20,7 +40,27 @@ pub trait Landscape {
         self.rain(|_| cnt.clone(), return_result)
     }
 
+    // Like `rain`, but also evaporates standing water and lets the ground
+    // absorb some of it afterwards, so water doesn't accumulate forever.
+    fn rain_with_climate(
+        &mut self,
+        rain_distr: impl Fn(usize) -> Self::PointHeight,
+        return_result: bool,
+    ) -> Result<&[Self::PointHeight]>;
+
+    fn rain_with_climate_uniform(
+        &mut self,
+        cnt: Self::PointHeight,
+        return_result: bool,
+    ) -> Result<&[Self::PointHeight]> {
+        self.rain_with_climate(|_| cnt.clone(), return_result)
+    }
+
     fn precision(&self) -> Self::PointHeight;
+
+    // Returns a parallel `Vec` classifying the current state of each point's
+    // water (dry, on a slope, or pooled at a local low point of the ground).
+    fn water_states(&self) -> Vec<WaterState>;
 }
 
 pub fn start(steps: usize, points: Vec<f64>) -> Result<()> {
@@ -35,6 +75,81 @@ pub fn start(steps: usize, points: Vec<f64>) -> Result<()> {
                         .as_bytes(),
                 )?;
                 stdout.write(&[b'\n'])?;
+
+                let states: String = landscape
+                    .water_states()
+                    .iter()
+                    .map(|s| s.as_char())
+                    .collect();
+                stdout.write_all(states.as_bytes())?;
+                stdout.write(&[b'\n'])?;
+            }
+            Err(e) => {
+                eprintln!("Error during {} st/th invocation of rain(): {}", n, e);
+            }
+        }
+    }
+    return Ok(());
+}
+
+// Same as `start`, but rains via `rain_with_climate_uniform` so standing
+// water evaporates and the ground absorbs some of it between steps.
+pub fn start_with_climate(steps: usize, points: Vec<f64>) -> Result<()> {
+    let mut stdout = stdout();
+    let mut landscape = handle(points);
+    for n in 1..=steps {
+        match landscape.rain_with_climate_uniform(RAIN_DENSITY.into(), true) {
+            Ok(water_levels) => {
+                stdout.write_all(
+                    format!("{:?}", water_levels)
+                        .trim_matches(&['[', ']'] as &[_])
+                        .as_bytes(),
+                )?;
+                stdout.write(&[b'\n'])?;
+            }
+            Err(e) => {
+                eprintln!("Error during {} st/th invocation of rain(): {}", n, e);
+            }
+        }
+    }
+    return Ok(());
+}
+
+// Reports every basin in the ground profile, independent of any rain: its
+// index range, floor height, spill height, and how much water it can hold
+// before overflowing.
+pub fn start_basins(points: Vec<f64>) -> Result<()> {
+    let mut stdout = stdout();
+    let landscape = handle(points);
+    for basin in landscape.basins() {
+        stdout.write_all(
+            format!(
+                "[{}, {}]: floor={:.4} spill={:.4} capacity={:.4}\n",
+                basin.start, basin.end, basin.floor, basin.spill_height, basin.capacity
+            )
+            .as_bytes(),
+        )?;
+    }
+    Ok(())
+}
+
+// Same as `start`, but for a `GridLandscape`: water levels are printed one
+// grid row per line instead of a single flat line.
+pub fn start_grid(steps: usize, rows: Vec<Vec<f64>>) -> Result<()> {
+    let mut stdout = stdout();
+    let mut landscape = handle_grid(rows);
+    let width = landscape.width();
+    for n in 1..=steps {
+        match landscape.rain_uniform(RAIN_DENSITY.into(), true) {
+            Ok(water_levels) => {
+                for row in water_levels.chunks(width) {
+                    stdout.write_all(
+                        format!("{:?}", row)
+                            .trim_matches(&['[', ']'] as &[_])
+                            .as_bytes(),
+                    )?;
+                    stdout.write(&[b'\n'])?;
+                }
             }
             Err(e) => {
                 eprintln!("Error during {} st/th invocation of rain(): {}", n, e);
@@ -1,4 +1,4 @@
-use crate::app::{start, Landscape};
+use crate::app::{start, start_basins, start_grid, start_with_climate, Landscape};
 
 // Amount of rain that falls onto one point (segment) in one step (1h).
 const RAIN_DENSITY: f64 = 1.0;
@@ -7,17 +7,41 @@ mod app;
 mod rain_landscapes;
 mod util;
 
-fn handle(points_heights: Vec<f64>) -> impl Landscape {
+fn handle(points_heights: Vec<f64>) -> rain_landscapes::Landscape {
     rain_landscapes::Landscape::create(points_heights)
 }
 
+fn handle_grid(rows: Vec<Vec<f64>>) -> rain_landscapes::GridLandscape {
+    rain_landscapes::GridLandscape::create(rows)
+}
+
 // Program main function.
 fn main() {
     println!("Enter rain hours");
     let steps = util::read_input_rain_hours();
 
-    println!("Enter landscape heights: ,ex: 1 2 3");
-    let points = util::read_input();
+    println!("Enter mode: 1 for a 1D cross-section, 2 for a 2D grid, 3 for a 1D cross-section with evaporation/absorption, 4 to report the basins in a 1D cross-section");
+    let mode = util::read_input_mode();
+
+    if mode == 2 {
+        println!("Enter landscape heights row by row, ex: 1 2 3 (blank line to finish)");
+        let rows = util::read_input_grid();
+
+        start_grid(steps, rows);
+    } else if mode == 3 {
+        println!("Enter landscape heights: ,ex: 1 2 3");
+        let points = util::read_input();
+
+        start_with_climate(steps, points);
+    } else if mode == 4 {
+        println!("Enter landscape heights: ,ex: 1 2 3");
+        let points = util::read_input();
+
+        start_basins(points);
+    } else {
+        println!("Enter landscape heights: ,ex: 1 2 3");
+        let points = util::read_input();
 
-    start(steps, points);
+        start(steps, points);
+    }
 }
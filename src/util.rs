@@ -10,14 +10,48 @@ pub fn read_input() -> Vec<f64> {
     return points;
 }
 
-pub fn read_input_rain_hours() -> usize {
+// Reads a grid of heights, one whitespace-separated row per line, until a
+// blank line (or EOF) terminates input.
+pub fn read_input_grid() -> Vec<Vec<f64>> {
+    let mut rows = Vec::new();
+    loop {
+        let mut input = String::new();
+        if std::io::stdin().read_line(&mut input).unwrap() == 0 {
+            break;
+        }
+        if input.trim().is_empty() {
+            break;
+        }
+        let row = input
+            .split_whitespace()
+            .map(|x| x.parse::<f64>())
+            .collect::<Result<Vec<f64>, _>>()
+            .unwrap();
+        rows.push(row);
+    }
+
+    return rows;
+}
+
+// Reads a single non-negative integer from one line of input, shared by the
+// rain-hours and mode prompts below.
+fn read_input_usize() -> usize {
     let mut input = String::new();
     std::io::stdin().read_line(&mut input).unwrap();
-    let rain_hours = input
+    let values = input
         .split_whitespace()
         .map(|x| x.parse::<usize>())
         .collect::<Result<Vec<usize>, _>>()
         .unwrap();
 
-    return rain_hours[0];
+    return values[0];
+}
+
+pub fn read_input_rain_hours() -> usize {
+    read_input_usize()
+}
+
+// Reads the CLI mode selector (1/2/3/4).
+pub fn read_input_mode() -> usize {
+    read_input_usize()
 }